@@ -11,7 +11,10 @@ use luminance::framebuffer::FramebufferError;
 use luminance::texture::Dim2;
 pub use luminance_gl::gl33::StateQueryError;
 use luminance_gl::GL33;
-pub use luminance_windowing::{CursorMode, WindowDim, WindowOpt};
+pub use luminance_windowing::{
+  CursorMode, SwapInterval as WindowSwapInterval, WindowDim, WindowOpt,
+};
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 use std::error;
 use std::fmt;
 use std::os::raw::c_void;
@@ -75,6 +78,14 @@ pub struct GlfwSurface {
   pub events_rx: Receiver<(f64, WindowEvent)>,
   /// OpenGL 3.3 state.
   gl: GL33,
+  /// User debug-message callback, kept alive for as long as the surface so the address handed to
+  /// the GL debug callback stays valid. `None` unless the surface was created with
+  /// [`GlfwSurface::new_gl33_debug`] and a user callback.
+  _debug_cbk: Option<Box<DebugCallback>>,
+  /// Fixed back-buffer size for off-screen surfaces. When set, [`GlfwSurface::back_buffer`] uses it
+  /// instead of the hidden window’s framebuffer size. `None` for on-screen surfaces, which track
+  /// the window.
+  back_buffer_size: Option<[u32; 2]>,
 }
 
 impl GlfwSurface {
@@ -83,55 +94,77 @@ impl GlfwSurface {
   where
     S: AsRef<str>,
   {
-    #[cfg(feature = "log-errors")]
-    let error_cbk = glfw::LOG_ERRORS;
-    #[cfg(not(feature = "log-errors"))]
-    let error_cbk = glfw::FAIL_ON_ERRORS;
-
-    let mut glfw = glfw::init(error_cbk).map_err(GlfwSurfaceError::InitError)?;
-
-    // OpenGL hints
-    glfw.window_hint(glfw::WindowHint::OpenGlProfile(
-      glfw::OpenGlProfileHint::Core,
-    ));
-    glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(true));
-    glfw.window_hint(glfw::WindowHint::ContextVersionMajor(3));
-    glfw.window_hint(glfw::WindowHint::ContextVersionMinor(3));
-    glfw.window_hint(glfw::WindowHint::Samples(*win_opt.num_samples()));
-
-    // open a window in windowed or fullscreen mode
-    let title = title.as_ref();
-    let dim = win_opt.dim;
-    let (mut window, events_rx) = match dim {
-      WindowDim::Windowed { width, height } => glfw
-        .create_window(width, height, title, WindowMode::Windowed)
-        .ok_or(GlfwSurfaceError::WindowCreationFailed)?,
-      WindowDim::Fullscreen => glfw.with_primary_monitor(|glfw, monitor| {
-        let monitor = monitor.ok_or(GlfwSurfaceError::NoPrimaryMonitor)?;
-        let vmode = monitor
-          .get_video_mode()
-          .ok_or(GlfwSurfaceError::NoVideoMode)?;
-        let (w, h) = (vmode.width, vmode.height);
-
-        Ok(
-          glfw
-            .create_window(w, h, title, WindowMode::FullScreen(monitor))
-            .ok_or(GlfwSurfaceError::WindowCreationFailed)?,
-        )
-      })?,
-      WindowDim::FullscreenRestricted { width, height } => {
-        glfw.with_primary_monitor(|glfw, monitor| {
-          let monitor = monitor.ok_or(GlfwSurfaceError::NoPrimaryMonitor)?;
-
-          Ok(
-            glfw
-              .create_window(width, height, title, WindowMode::FullScreen(monitor))
-              .ok_or(GlfwSurfaceError::WindowCreationFailed)?,
-          )
-        })?
-      }
-    };
+    let mut glfw = init_glfw(&win_opt, false)?;
+    let (window, events_rx) = open_window(&mut glfw, title.as_ref(), win_opt.dim)?;
+
+    Self::finish(&mut glfw, window, events_rx, &win_opt, false, None)
+  }
+
+  /// Create a [`GlfwSurface`] with OpenGL debug output enabled.
+  ///
+  /// Borrowing the `KHR_debug` capability, this requests an OpenGL debug context
+  /// ([`glfw::WindowHint::OpenGlDebugContext`]) and registers a debug-message callback once the
+  /// [`GL33`] state is acquired. Decoded [`GlDebugMessage`]s are then delivered either to the
+  /// user-provided `debug_cbk`, or — when `None` is passed — logged through the `log` crate behind
+  /// the `log-errors` feature. This surfaces shader and driver errors at their call site instead of
+  /// letting them silently corrupt state.
+  pub fn new_gl33_debug<S>(
+    title: S,
+    win_opt: WindowOpt,
+    debug_cbk: Option<Box<dyn FnMut(GlDebugMessage)>>,
+  ) -> Result<Self, GlfwSurfaceError>
+  where
+    S: AsRef<str>,
+  {
+    let mut glfw = init_glfw(&win_opt, true)?;
+    let (window, events_rx) = open_window(&mut glfw, title.as_ref(), win_opt.dim)?;
+
+    Self::finish(&mut glfw, window, events_rx, &win_opt, true, debug_cbk)
+  }
+
+  /// Create an off-screen [`GlfwSurface`] backed by a hidden window.
+  ///
+  /// No window is ever mapped on screen: the backing GLFW window is created with
+  /// [`glfw::WindowHint::Visible`] set to `false`, which keeps a real OpenGL context around while
+  /// never requiring a display. This makes the surface suitable for CI, thumbnail generation and
+  /// server-side rendering, where you render into a user-sized target and read it back rather than
+  /// presenting frames.
+  ///
+  /// `width` and `height` size the hidden framebuffer and therefore the [`back_buffer`]. The
+  /// [`WindowDim`] carried by `win_opt` is ignored.
+  ///
+  /// [`back_buffer`]: GlfwSurface::back_buffer
+  pub fn new_gl33_offscreen(
+    width: u32,
+    height: u32,
+    win_opt: WindowOpt,
+  ) -> Result<Self, GlfwSurfaceError> {
+    let mut glfw = init_glfw(&win_opt, false)?;
+
+    // keep the window off-screen; we only need its context
+    glfw.window_hint(glfw::WindowHint::Visible(false));
+
+    let (window, events_rx) = glfw
+      .create_window(width, height, "", WindowMode::Windowed)
+      .ok_or(GlfwSurfaceError::WindowCreationFailed)?;
+
+    let mut surface = Self::finish(&mut glfw, window, events_rx, &win_opt, false, None)?;
+    // pin the back buffer to the requested target, independent of the hidden window
+    surface.back_buffer_size = Some([width, height]);
+
+    Ok(surface)
+  }
 
+  /// Make the freshly created window current, apply the remaining [`WindowOpt`] settings and
+  /// acquire the [`GL33`] state.
+  fn finish(
+    glfw: &mut glfw::Glfw,
+    mut window: Window,
+    events_rx: Receiver<(f64, WindowEvent)>,
+    win_opt: &WindowOpt,
+    debug: bool,
+    debug_cbk: Option<Box<dyn FnMut(GlDebugMessage)>>,
+  ) -> Result<Self, GlfwSurfaceError> {
     window.make_current();
 
     match win_opt.cursor_mode() {
@@ -141,32 +174,509 @@ impl GlfwSurface {
     }
 
     window.set_all_polling(true);
-    glfw.set_swap_interval(SwapInterval::Sync(1));
+    glfw.set_swap_interval(match win_opt.swap_interval() {
+      WindowSwapInterval::None => SwapInterval::None,
+      WindowSwapInterval::Sync(n) => SwapInterval::Sync(n),
+      WindowSwapInterval::Adaptive => SwapInterval::Adaptive,
+    });
 
-    // init OpenGL
-    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+    // init OpenGL and acquire the GL33 state by loading symbols from the window’s context
+    let gl = load_gl33(|s| window.get_proc_address(s) as *const c_void)?;
 
-    let gl = GL33::new().map_err(GlfwSurfaceError::GraphicsStateError)?;
-    let surface = GlfwSurface {
+    // box the user callback on the heap so its address stays stable while it lives on the surface,
+    // then hand that address to the GL debug callback as its user parameter; a null parameter
+    // selects the `log`-based fallback inside the trampoline
+    let mut debug_cbk = debug_cbk.map(Box::new);
+    if debug {
+      // derive the user parameter from a *mutable* borrow: the trampoline reconstitutes it as
+      // `&mut DebugCallback`, so the provenance must be mutable to stay sound
+      let user_param = debug_cbk
+        .as_mut()
+        .map(|cbk| &mut **cbk as *mut DebugCallback as *mut c_void)
+        .unwrap_or(std::ptr::null_mut());
+      unsafe { enable_gl_debug(user_param) };
+    }
+
+    Ok(GlfwSurface {
       window,
       events_rx,
       gl,
+      _debug_cbk: debug_cbk,
+      back_buffer_size: None,
+    })
+  }
+
+  /// Get the back buffer.
+  ///
+  /// For on-screen surfaces the size tracks the window’s framebuffer; for off-screen surfaces it is
+  /// the fixed target given to [`new_gl33_offscreen`].
+  ///
+  /// [`new_gl33_offscreen`]: GlfwSurface::new_gl33_offscreen
+  pub fn back_buffer(&mut self) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
+    // make this surface’s window current so rendering targets it, not a shared window that may
+    // have been made current more recently
+    self.window.make_current();
+    let size = self.back_buffer_size.unwrap_or_else(|| {
+      let (w, h) = self.window.get_framebuffer_size();
+      [w as u32, h as u32]
+    });
+    Framebuffer::back_buffer(self, size)
+  }
+
+  /// Make this surface’s context current.
+  ///
+  /// Useful after rendering through a [`SharedWindow`], to switch rendering back to this surface.
+  pub fn make_current(&mut self) {
+    self.window.make_current();
+  }
+
+  /// Create an additional window sharing this surface’s OpenGL context.
+  ///
+  /// The new window is created with GLFW’s share-window mechanism, so it lives in the same object
+  /// namespace as this surface: textures, buffers and tessellations created through the original
+  /// [`GraphicsContext`] stay usable while the shared window is current. This is meant for
+  /// multi-viewport editors and tool windows (e.g. an inspector next to a viewport) that must not
+  /// duplicate GPU resources.
+  ///
+  /// The returned [`SharedWindow`] borrows this surface: only one of the two can render at a time,
+  /// the one that is current. Rendering helpers on the handle make its window current first.
+  pub fn new_shared_window<S>(
+    &mut self,
+    title: S,
+    win_opt: WindowOpt,
+  ) -> Result<SharedWindow<'_>, GlfwSurfaceError>
+  where
+    S: AsRef<str>,
+  {
+    let (width, height) = match win_opt.dim {
+      WindowDim::Windowed { width, height }
+      | WindowDim::FullscreenRestricted { width, height } => (width, height),
+      WindowDim::Fullscreen => {
+        let (w, h) = self.window.get_framebuffer_size();
+        (w as u32, h as u32)
+      }
     };
 
-    Ok(surface)
+    let (mut window, events_rx) = self
+      .window
+      .create_shared(width, height, title.as_ref(), WindowMode::Windowed)
+      .ok_or(GlfwSurfaceError::WindowCreationFailed)?;
+
+    window.make_current();
+
+    match win_opt.cursor_mode() {
+      CursorMode::Visible => window.set_cursor_mode(GlfwCursorMode::Normal),
+      CursorMode::Invisible => window.set_cursor_mode(GlfwCursorMode::Hidden),
+      CursorMode::Disabled => window.set_cursor_mode(GlfwCursorMode::Disabled),
+    }
+
+    window.set_all_polling(true);
+
+    Ok(SharedWindow {
+      window,
+      events_rx,
+      surface: self,
+    })
   }
+}
 
-  /// Get the back buffer.
+unsafe impl GraphicsContext for GlfwSurface {
+  type Backend = GL33;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.gl
+  }
+}
+
+/// A secondary window sharing the [`GL33`] context of the [`GlfwSurface`] it was created from.
+///
+/// Obtained via [`GlfwSurface::new_shared_window`]. It carries its own window and event queue but
+/// borrows the parent surface for its backend, so GPU resources are shared rather than duplicated.
+/// Because the two windows share a single context, only the current one can render; the helpers
+/// here make this window current before handing back a [`Framebuffer`].
+pub struct SharedWindow<'a> {
+  /// Wrapped GLFW window.
+  pub window: Window,
+  /// Wrapped GLFW events queue, specific to this window.
+  pub events_rx: Receiver<(f64, WindowEvent)>,
+  /// Parent surface owning the shared [`GL33`] state.
+  surface: &'a mut GlfwSurface,
+}
+
+impl<'a> SharedWindow<'a> {
+  /// Make this window’s context current before rendering to it.
+  pub fn make_current(&mut self) {
+    self.window.make_current();
+  }
+
+  /// Get the back buffer, keyed to this window’s framebuffer size.
+  ///
+  /// The window is made current first, so rendering through the shared context targets it.
   pub fn back_buffer(&mut self) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
+    self.window.make_current();
     let (w, h) = self.window.get_framebuffer_size();
     Framebuffer::back_buffer(self, [w as u32, h as u32])
   }
 }
 
-unsafe impl GraphicsContext for GlfwSurface {
+unsafe impl<'a> GraphicsContext for SharedWindow<'a> {
+  type Backend = GL33;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    self.surface.backend()
+  }
+}
+
+/// Initialize GLFW and set the common OpenGL window hints shared by every surface.
+///
+/// This requests a core-profile, forward-compatible OpenGL context of the version given by
+/// `win_opt`, and forwards its multisample count, sRGB flag and depth/stencil bit counts; `debug`
+/// additionally requests an OpenGL debug context. The error callback is selected from the
+/// `log-errors` feature, as elsewhere in this crate.
+fn init_glfw(win_opt: &WindowOpt, debug: bool) -> Result<glfw::Glfw, GlfwSurfaceError> {
+  #[cfg(feature = "log-errors")]
+  let error_cbk = glfw::LOG_ERRORS;
+  #[cfg(not(feature = "log-errors"))]
+  let error_cbk = glfw::FAIL_ON_ERRORS;
+
+  let mut glfw = glfw::init(error_cbk).map_err(GlfwSurfaceError::InitError)?;
+
+  // OpenGL hints
+  let (major, minor) = win_opt.gl_version();
+  glfw.window_hint(glfw::WindowHint::OpenGlProfile(
+    glfw::OpenGlProfileHint::Core,
+  ));
+  glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(true));
+  glfw.window_hint(glfw::WindowHint::ContextVersionMajor(major as u32));
+  glfw.window_hint(glfw::WindowHint::ContextVersionMinor(minor as u32));
+  glfw.window_hint(glfw::WindowHint::Samples(*win_opt.num_samples()));
+
+  // framebuffer format hints
+  glfw.window_hint(glfw::WindowHint::SRgbCapable(win_opt.srgb_capable()));
+  glfw.window_hint(glfw::WindowHint::DepthBits(win_opt.depth_bits()));
+  glfw.window_hint(glfw::WindowHint::StencilBits(win_opt.stencil_bits()));
+
+  if debug {
+    glfw.window_hint(glfw::WindowHint::OpenGlDebugContext(true));
+  }
+
+  Ok(glfw)
+}
+
+/// Open a GLFW window honoring the requested [`WindowDim`].
+fn open_window(
+  glfw: &mut glfw::Glfw,
+  title: &str,
+  dim: WindowDim,
+) -> Result<(Window, Receiver<(f64, WindowEvent)>), GlfwSurfaceError> {
+  match dim {
+    WindowDim::Windowed { width, height } => glfw
+      .create_window(width, height, title, WindowMode::Windowed)
+      .ok_or(GlfwSurfaceError::WindowCreationFailed),
+    WindowDim::Fullscreen => glfw.with_primary_monitor(|glfw, monitor| {
+      let monitor = monitor.ok_or(GlfwSurfaceError::NoPrimaryMonitor)?;
+      let vmode = monitor
+        .get_video_mode()
+        .ok_or(GlfwSurfaceError::NoVideoMode)?;
+      let (w, h) = (vmode.width, vmode.height);
+
+      glfw
+        .create_window(w, h, title, WindowMode::FullScreen(monitor))
+        .ok_or(GlfwSurfaceError::WindowCreationFailed)
+    }),
+    WindowDim::FullscreenRestricted { width, height } => {
+      glfw.with_primary_monitor(|glfw, monitor| {
+        let monitor = monitor.ok_or(GlfwSurfaceError::NoPrimaryMonitor)?;
+
+        glfw
+          .create_window(width, height, title, WindowMode::FullScreen(monitor))
+          .ok_or(GlfwSurfaceError::WindowCreationFailed)
+      })
+    }
+  }
+}
+
+/// Load the OpenGL symbols and acquire a [`GL33`] state.
+///
+/// `loader` resolves a symbol name to its address, exactly like
+/// [`glfw::Window::get_proc_address`]. This is the single place where the GL state is actually
+/// acquired; every surface in this crate ultimately goes through it.
+fn load_gl33<L>(loader: L) -> Result<GL33, GlfwSurfaceError>
+where
+  L: FnMut(&str) -> *const c_void,
+{
+  gl::load_with(loader);
+  GL33::new().map_err(GlfwSurfaceError::GraphicsStateError)
+}
+
+/// A [`GL33`] context driven by a foreign, non-GLFW window.
+///
+/// Where [`GlfwSurface`] owns the window it renders to, this type owns only the OpenGL state. It is
+/// built from any handle implementing [`HasRawWindowHandle`] — a window created by `winit`, SDL, an
+/// embedded view, … — together with a symbol loader and a framebuffer-size closure. The caller
+/// remains responsible for creating the context, making it current and swapping buffers; this type
+/// only takes over the **luminance** side once GL is available.
+///
+/// `raw_window_handle` resolving the loader automatically is not possible through
+/// [`raw_window_handle`] alone — it exposes the window handle, not a GL symbol loader — so the
+/// loader is supplied by the caller, typically the `get_proc_address` of the toolkit that owns the
+/// window.
+pub struct RawGL33Surface {
+  gl: GL33,
+  raw_handle: RawWindowHandle,
+  fb_size: Box<dyn FnMut() -> [u32; 2]>,
+}
+
+impl RawGL33Surface {
+  /// Build a surface from a raw window handle.
+  ///
+  /// - `handle` is the foreign window the GL context was created for; it must be current. Its
+  ///   [`RawWindowHandle`] is retained and can be read back with [`raw_window_handle`].
+  /// - `loader` resolves OpenGL symbol names to their addresses (e.g. via the platform’s
+  ///   `get_proc_address`).
+  /// - `fb_size` yields the current framebuffer size and is queried by [`back_buffer`]; pass a
+  ///   closure reading it back from the owning toolkit so resizes are picked up.
+  ///
+  /// [`back_buffer`]: RawGL33Surface::back_buffer
+  /// [`raw_window_handle`]: RawGL33Surface::raw_window_handle
+  pub fn from_raw_handle<H, L, F>(
+    handle: &H,
+    loader: L,
+    fb_size: F,
+  ) -> Result<Self, GlfwSurfaceError>
+  where
+    H: HasRawWindowHandle,
+    L: FnMut(&str) -> *const c_void,
+    F: FnMut() -> [u32; 2] + 'static,
+  {
+    let raw_handle = handle.raw_window_handle();
+    let gl = load_gl33(loader)?;
+
+    Ok(RawGL33Surface {
+      gl,
+      raw_handle,
+      fb_size: Box::new(fb_size),
+    })
+  }
+
+  /// The [`RawWindowHandle`] of the foreign window backing this surface.
+  pub fn raw_window_handle(&self) -> RawWindowHandle {
+    self.raw_handle
+  }
+
+  /// Get the back buffer, sized from the framebuffer-size closure given at construction.
+  pub fn back_buffer(&mut self) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
+    let size = (self.fb_size)();
+    Framebuffer::back_buffer(self, size)
+  }
+}
+
+unsafe impl GraphicsContext for RawGL33Surface {
   type Backend = GL33;
 
   fn backend(&mut self) -> &mut Self::Backend {
     &mut self.gl
   }
 }
+
+/// Boxed user callback receiving decoded OpenGL debug messages.
+type DebugCallback = Box<dyn FnMut(GlDebugMessage)>;
+
+/// Origin of an OpenGL debug message, decoded from the `GL_DEBUG_SOURCE_*` enums.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GlDebugSource {
+  /// `GL_DEBUG_SOURCE_API`.
+  Api,
+  /// `GL_DEBUG_SOURCE_WINDOW_SYSTEM`.
+  WindowSystem,
+  /// `GL_DEBUG_SOURCE_SHADER_COMPILER`.
+  ShaderCompiler,
+  /// `GL_DEBUG_SOURCE_THIRD_PARTY`.
+  ThirdParty,
+  /// `GL_DEBUG_SOURCE_APPLICATION`.
+  Application,
+  /// `GL_DEBUG_SOURCE_OTHER`, or an unrecognized source.
+  Other,
+}
+
+/// Nature of an OpenGL debug message, decoded from the `GL_DEBUG_TYPE_*` enums.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GlDebugType {
+  /// `GL_DEBUG_TYPE_ERROR`.
+  Error,
+  /// `GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR`.
+  DeprecatedBehavior,
+  /// `GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR`.
+  UndefinedBehavior,
+  /// `GL_DEBUG_TYPE_PORTABILITY`.
+  Portability,
+  /// `GL_DEBUG_TYPE_PERFORMANCE`.
+  Performance,
+  /// `GL_DEBUG_TYPE_MARKER`.
+  Marker,
+  /// `GL_DEBUG_TYPE_PUSH_GROUP`.
+  PushGroup,
+  /// `GL_DEBUG_TYPE_POP_GROUP`.
+  PopGroup,
+  /// `GL_DEBUG_TYPE_OTHER`, or an unrecognized type.
+  Other,
+}
+
+/// Severity of an OpenGL debug message, decoded from the `GL_DEBUG_SEVERITY_*` enums.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GlDebugSeverity {
+  /// `GL_DEBUG_SEVERITY_HIGH`.
+  High,
+  /// `GL_DEBUG_SEVERITY_MEDIUM`.
+  Medium,
+  /// `GL_DEBUG_SEVERITY_LOW`.
+  Low,
+  /// `GL_DEBUG_SEVERITY_NOTIFICATION`, or an unrecognized severity.
+  Notification,
+}
+
+/// A decoded OpenGL debug message, as delivered through a `KHR_debug` callback.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct GlDebugMessage {
+  /// Where the message originated.
+  pub source: GlDebugSource,
+  /// What kind of message it is.
+  pub ty: GlDebugType,
+  /// Implementation-defined message identifier.
+  pub id: u32,
+  /// How serious the message is.
+  pub severity: GlDebugSeverity,
+  /// Human-readable message text.
+  pub message: String,
+}
+
+impl GlDebugSource {
+  fn from_raw(source: gl::types::GLenum) -> Self {
+    match source {
+      gl::DEBUG_SOURCE_API => GlDebugSource::Api,
+      gl::DEBUG_SOURCE_WINDOW_SYSTEM => GlDebugSource::WindowSystem,
+      gl::DEBUG_SOURCE_SHADER_COMPILER => GlDebugSource::ShaderCompiler,
+      gl::DEBUG_SOURCE_THIRD_PARTY => GlDebugSource::ThirdParty,
+      gl::DEBUG_SOURCE_APPLICATION => GlDebugSource::Application,
+      _ => GlDebugSource::Other,
+    }
+  }
+}
+
+impl GlDebugType {
+  fn from_raw(ty: gl::types::GLenum) -> Self {
+    match ty {
+      gl::DEBUG_TYPE_ERROR => GlDebugType::Error,
+      gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => GlDebugType::DeprecatedBehavior,
+      gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => GlDebugType::UndefinedBehavior,
+      gl::DEBUG_TYPE_PORTABILITY => GlDebugType::Portability,
+      gl::DEBUG_TYPE_PERFORMANCE => GlDebugType::Performance,
+      gl::DEBUG_TYPE_MARKER => GlDebugType::Marker,
+      gl::DEBUG_TYPE_PUSH_GROUP => GlDebugType::PushGroup,
+      gl::DEBUG_TYPE_POP_GROUP => GlDebugType::PopGroup,
+      _ => GlDebugType::Other,
+    }
+  }
+}
+
+impl GlDebugSeverity {
+  fn from_raw(severity: gl::types::GLenum) -> Self {
+    match severity {
+      gl::DEBUG_SEVERITY_HIGH => GlDebugSeverity::High,
+      gl::DEBUG_SEVERITY_MEDIUM => GlDebugSeverity::Medium,
+      gl::DEBUG_SEVERITY_LOW => GlDebugSeverity::Low,
+      _ => GlDebugSeverity::Notification,
+    }
+  }
+}
+
+/// Enable `GL_DEBUG_OUTPUT` and register [`debug_callback_trampoline`] with `user_param`.
+///
+/// `user_param` is either the address of a [`DebugCallback`] living on the [`GlfwSurface`], or null
+/// to select the `log`-based fallback inside the trampoline.
+///
+/// # Safety
+///
+/// A current OpenGL 3.3 debug context must exist, and — when non-null — `user_param` must point to a
+/// [`DebugCallback`] that outlives the context.
+unsafe fn enable_gl_debug(user_param: *mut c_void) {
+  gl::Enable(gl::DEBUG_OUTPUT);
+  gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+  gl::DebugMessageCallback(Some(debug_callback_trampoline), user_param);
+}
+
+/// C trampoline handed to `glDebugMessageCallback`.
+///
+/// It decodes the raw enums and message string into a [`GlDebugMessage`] and dispatches it to the
+/// user callback addressed by `user_param`, or to the `log` crate when `user_param` is null and the
+/// `log-errors` feature is enabled.
+extern "system" fn debug_callback_trampoline(
+  source: gl::types::GLenum,
+  ty: gl::types::GLenum,
+  id: gl::types::GLuint,
+  severity: gl::types::GLenum,
+  length: gl::types::GLsizei,
+  message: *const gl::types::GLchar,
+  user_param: *mut c_void,
+) {
+  // `from_raw_parts` requires a non-null pointer even for a zero-length slice, so guard the null
+  // case rather than feeding it a possibly-null `message`
+  let text = if message.is_null() {
+    String::new()
+  } else {
+    let bytes =
+      unsafe { std::slice::from_raw_parts(message as *const u8, length.max(0) as usize) };
+    String::from_utf8_lossy(bytes).into_owned()
+  };
+  let message = GlDebugMessage {
+    source: GlDebugSource::from_raw(source),
+    ty: GlDebugType::from_raw(ty),
+    id: id as u32,
+    severity: GlDebugSeverity::from_raw(severity),
+    message: text,
+  };
+
+  if user_param.is_null() {
+    deliver_to_log(&message);
+  } else {
+    // SAFETY: when non-null, `user_param` is the address of a `DebugCallback` owned by the
+    // surface, which keeps it alive for as long as this trampoline can be called.
+    let cbk = unsafe { &mut *(user_param as *mut DebugCallback) };
+    cbk(message);
+  }
+}
+
+/// Fallback delivery of a debug message through the `log` crate, gated behind `log-errors`.
+#[allow(unused_variables)]
+fn deliver_to_log(message: &GlDebugMessage) {
+  #[cfg(feature = "log-errors")]
+  {
+    match message.severity {
+      GlDebugSeverity::High => log::error!(
+        "GL debug [{:?}/{:?}] #{}: {}",
+        message.source,
+        message.ty,
+        message.id,
+        message.message
+      ),
+      GlDebugSeverity::Medium | GlDebugSeverity::Low => log::warn!(
+        "GL debug [{:?}/{:?}] #{}: {}",
+        message.source,
+        message.ty,
+        message.id,
+        message.message
+      ),
+      GlDebugSeverity::Notification => log::info!(
+        "GL debug [{:?}/{:?}] #{}: {}",
+        message.source,
+        message.ty,
+        message.id,
+        message.message
+      ),
+    }
+  }
+}
@@ -0,0 +1,192 @@
+//! Common windowing types for [luminance](https://crates.io/crates/luminance) backends.
+//!
+//! This crate doesn’t create any window nor context on its own. It only provides backend-agnostic
+//! description types — [`WindowDim`], [`CursorMode`], [`SwapInterval`] and [`WindowOpt`] — that the
+//! actual windowing backends (such as `luminance-glfw`) read when they build a surface.
+
+#![deny(missing_docs)]
+
+/// Dimension of a window to create.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowDim {
+  /// Windowed mode with the given width and height.
+  Windowed {
+    /// Width of the window.
+    width: u32,
+    /// Height of the window.
+    height: u32,
+  },
+  /// Fullscreen mode, using the primary monitor’s current video mode.
+  Fullscreen,
+  /// Fullscreen mode restricted to the given width and height.
+  FullscreenRestricted {
+    /// Width of the window.
+    width: u32,
+    /// Height of the window.
+    height: u32,
+  },
+}
+
+/// Cursor mode to use with a window.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CursorMode {
+  /// The cursor is visible.
+  Visible,
+  /// The cursor is invisible but not constrained.
+  Invisible,
+  /// The cursor is invisible and locked to the window.
+  Disabled,
+}
+
+/// Buffer-swap synchronization requested for a window.
+///
+/// This is the backend-neutral counterpart of the swap-interval notion every windowing toolkit
+/// exposes; backends map it to their own representation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SwapInterval {
+  /// Don’t synchronize buffer swaps — present as fast as possible (vsync off).
+  None,
+  /// Synchronize swaps every `n` vertical refreshes (`Sync(1)` is the usual vsync).
+  Sync(u32),
+  /// Adaptive vsync: synchronize when the frame rate keeps up, tear rather than stall otherwise.
+  Adaptive,
+}
+
+/// Set of options used to create a window.
+///
+/// Build one with [`WindowOpt::default`] and refine it with the `set_*` methods, which consume and
+/// return the value so they can be chained.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowOpt {
+  /// Dimension of the window to create.
+  pub dim: WindowDim,
+  num_samples: Option<u32>,
+  cursor_mode: CursorMode,
+  gl_version: (u8, u8),
+  swap_interval: SwapInterval,
+  srgb_capable: bool,
+  depth_bits: Option<u32>,
+  stencil_bits: Option<u32>,
+}
+
+impl Default for WindowOpt {
+  /// Default window options.
+  ///
+  /// The defaults reproduce the historically hardcoded backend behavior: a core-profile OpenGL 3.3
+  /// context, vsync on (`Sync(1)`), a non-sRGB framebuffer with 24 depth and 8 stencil bits and no
+  /// multisampling.
+  fn default() -> Self {
+    WindowOpt {
+      dim: WindowDim::Windowed {
+        width: 960,
+        height: 540,
+      },
+      num_samples: None,
+      cursor_mode: CursorMode::Visible,
+      gl_version: (3, 3),
+      swap_interval: SwapInterval::Sync(1),
+      srgb_capable: false,
+      depth_bits: Some(24),
+      stencil_bits: Some(8),
+    }
+  }
+}
+
+impl WindowOpt {
+  /// Number of samples to use for multisampling, if any.
+  pub fn num_samples(&self) -> &Option<u32> {
+    &self.num_samples
+  }
+
+  /// Set the number of samples to use for multisampling.
+  pub fn set_num_samples<S>(self, num_samples: S) -> Self
+  where
+    S: Into<Option<u32>>,
+  {
+    WindowOpt {
+      num_samples: num_samples.into(),
+      ..self
+    }
+  }
+
+  /// Cursor mode to use.
+  pub fn cursor_mode(&self) -> CursorMode {
+    self.cursor_mode
+  }
+
+  /// Set the cursor mode to use.
+  pub fn set_cursor_mode(self, cursor_mode: CursorMode) -> Self {
+    WindowOpt {
+      cursor_mode,
+      ..self
+    }
+  }
+
+  /// Requested OpenGL version as a `(major, minor)` pair.
+  pub fn gl_version(&self) -> (u8, u8) {
+    self.gl_version
+  }
+
+  /// Set the requested OpenGL version as a `(major, minor)` pair.
+  pub fn set_gl_version(self, gl_version: (u8, u8)) -> Self {
+    WindowOpt { gl_version, ..self }
+  }
+
+  /// Requested buffer-swap synchronization.
+  pub fn swap_interval(&self) -> SwapInterval {
+    self.swap_interval
+  }
+
+  /// Set the requested buffer-swap synchronization.
+  pub fn set_swap_interval(self, swap_interval: SwapInterval) -> Self {
+    WindowOpt {
+      swap_interval,
+      ..self
+    }
+  }
+
+  /// Whether an sRGB-capable default framebuffer is requested.
+  pub fn srgb_capable(&self) -> bool {
+    self.srgb_capable
+  }
+
+  /// Set whether an sRGB-capable default framebuffer is requested.
+  pub fn set_srgb_capable(self, srgb_capable: bool) -> Self {
+    WindowOpt {
+      srgb_capable,
+      ..self
+    }
+  }
+
+  /// Requested number of depth bits in the default framebuffer, if any.
+  pub fn depth_bits(&self) -> Option<u32> {
+    self.depth_bits
+  }
+
+  /// Set the requested number of depth bits in the default framebuffer.
+  pub fn set_depth_bits<B>(self, depth_bits: B) -> Self
+  where
+    B: Into<Option<u32>>,
+  {
+    WindowOpt {
+      depth_bits: depth_bits.into(),
+      ..self
+    }
+  }
+
+  /// Requested number of stencil bits in the default framebuffer, if any.
+  pub fn stencil_bits(&self) -> Option<u32> {
+    self.stencil_bits
+  }
+
+  /// Set the requested number of stencil bits in the default framebuffer.
+  pub fn set_stencil_bits<B>(self, stencil_bits: B) -> Self
+  where
+    B: Into<Option<u32>>,
+  {
+    WindowOpt {
+      stencil_bits: stencil_bits.into(),
+      ..self
+    }
+  }
+}
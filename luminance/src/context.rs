@@ -196,4 +196,29 @@ pub unsafe trait GraphicsContext: Sized {
   {
     Texture::new(self, size, mipmaps, sampler)
   }
+
+  /// Adopt an already-allocated texture without transferring ownership.
+  ///
+  /// Unlike [`new_texture`], no GPU storage is allocated: `raw` is a handle to a resource that
+  /// already exists — an OpenGL texture id, or a backend-native image imported from a foreign buffer — which
+  /// is wrapped into a [`Texture`] with no CPU round-trip. The backend validates the handle against
+  /// the pixel format `P` and records it as non-owned, so dropping the returned [`Texture`] leaves
+  /// the underlying resource alive. This is the building block for video-decode and compositor
+  /// interop, where frames arrive as ready-made GPU images.
+  ///
+  /// See the documentation of [`Texture::from_raw`] for further details.
+  ///
+  /// [`new_texture`]: GraphicsContext::new_texture
+  fn new_texture_from_raw<D, P>(
+    &mut self,
+    raw: <Self::Backend as TextureBackend<D, P>>::NativeHandle,
+    size: D::Size,
+  ) -> Result<Texture<Self::Backend, D, P>, TextureError>
+  where
+    Self::Backend: TextureBackend<D, P>,
+    D: Dimensionable,
+    P: Pixel,
+  {
+    Texture::from_raw(self, raw, size)
+  }
 }